@@ -1,14 +1,19 @@
 use crate::event::UpdateEvent;
-use crate::sequence::sequence;
 use crate::state::State::*;
 use crate::status::Status::*;
-use crate::when_all::when_all;
 use crate::{Behavior, Event, Status, UpdateArgs};
 // use serde_derive::{Deserialize, Serialize};
 
 /// The action is still running.
 pub const RUNNING: (Status, f64) = (Running, 0.0);
 
+/// Default cap on how many times a `While`/repeated `Sequence` loop may
+/// re-tick its body within a single `event` call before it is forced to
+/// yield `Running`. Guards against a repeated behavior that completes
+/// instantly without consuming any delta time, which would otherwise spin
+/// forever and starve the caller.
+pub const DEFAULT_MAX_ITERATIONS_PER_TICK: u32 = 4096;
+
 /// The arguments in the action callback.
 pub struct ActionArgs<'a, E: 'a, A: 'a, S: 'a> {
     /// The event.
@@ -23,6 +28,52 @@ pub struct ActionArgs<'a, E: 'a, A: 'a, S: 'a> {
     // pub data: Option<&'a mut D>,
 }
 
+/// Outcome of a single `BehaviorProcessor::process` call.
+pub enum ProcessResult<Error> {
+    /// The action is still running; carries the remaining delta time, same
+    /// as the `Running` half of the `(Status, f64)` pair returned by the
+    /// closure-based `event`.
+    Running(f64),
+    /// The action succeeded.
+    Success(f64),
+    /// The action failed.
+    Failure(f64),
+    /// The action hit a domain error that aborts the whole tick
+    /// immediately, short-circuiting out of any `Sequence`/`WhenAll`
+    /// nesting instead of reporting `Failure`.
+    Abort(Error),
+}
+
+/// A fallible alternative to the bare `FnMut(ActionArgs) -> (Status, f64)`
+/// closure accepted by `State::event`. Modeled on the processor/obligation
+/// pattern: actions report through `process`, which resolves to a terminal
+/// status or to `Abort` with a domain error that unwinds the entire tree.
+///
+/// Blanket-implemented for any closure with the old signature (see below),
+/// so existing `event`/`tick` callers are unaffected.
+pub trait BehaviorProcessor<E, A, S> {
+    /// The error an action may abort a tick with.
+    type Error;
+
+    /// Processes a single action invocation.
+    fn process(&mut self, args: ActionArgs<E, A, S>) -> ProcessResult<Self::Error>;
+}
+
+impl<E, A, S, F> BehaviorProcessor<E, A, S> for F
+where
+    F: FnMut(ActionArgs<E, A, S>) -> (Status, f64),
+{
+    type Error = std::convert::Infallible;
+
+    fn process(&mut self, args: ActionArgs<E, A, S>) -> ProcessResult<Self::Error> {
+        match self(args) {
+            (Running, dt) => ProcessResult::Running(dt),
+            (Success, dt) => ProcessResult::Success(dt),
+            (Failure, dt) => ProcessResult::Failure(dt),
+        }
+    }
+}
+
 /// Keeps track of a behavior.
 #[derive(Clone, serde::Deserialize, serde::Serialize, PartialEq)]
 pub enum State<A, S> {
@@ -50,13 +101,50 @@ pub enum State<A, S> {
     /// Keeps track of an `Sequence` behavior.
     SequenceState(Vec<Behavior<A>>, usize, Box<State<A, S>>),
     /// Keeps track of a `While` behavior.
-    WhileState(Box<State<A, S>>, Vec<Behavior<A>>, usize, Box<State<A, S>>),
+    ///
+    /// The trailing `u32` is the loop limit: the maximum number of times the
+    /// repeated behavior may be re-created and re-ticked within a single
+    /// `event` call without making progress (see `with_loop_limit`).
+    WhileState(Box<State<A, S>>, Vec<Behavior<A>>, usize, Box<State<A, S>>, u32),
     /// Keeps track of a `WhenAll` behavior.
     WhenAllState(Vec<Option<State<A, S>>>),
     /// Keeps track of a `WhenAny` behavior.
     WhenAnyState(Vec<Option<State<A, S>>>),
     /// Keeps track of an `After` behavior.
     AfterState(usize, Vec<State<A, S>>),
+    /// Keeps track of a `Parallel` behavior.
+    ///
+    /// `Vec<Option<State<A, S>>>`: Cursors of the children, replaced with
+    /// `None` once they report a terminal status.
+    ///
+    /// `usize`: Number of children that must succeed for the whole node to
+    /// succeed.
+    ///
+    /// `usize`: Number of children that must fail for the whole node to
+    /// fail.
+    ///
+    /// `usize`: Number of children that have succeeded so far.
+    ///
+    /// `usize`: Number of children that have failed so far.
+    ParallelState(Vec<Option<State<A, S>>>, usize, usize, usize, usize),
+    /// Keeps track of a `Repeat` behavior.
+    ///
+    /// `u32`: Number of iterations left, including the current one.
+    ///
+    /// `Box<Behavior<A>>`: The repeated behavior, used to build a fresh
+    /// cursor for the next iteration.
+    ///
+    /// `Box<State<A, S>>`: Cursor for the iteration currently running.
+    RepeatState(u32, Box<Behavior<A>>, Box<State<A, S>>),
+    /// Keeps track of a `Cooldown` behavior.
+    ///
+    /// `f64`: Cooldown duration in seconds.
+    ///
+    /// `f64`: Time elapsed since the child last returned a terminal status.
+    /// Starts equal to the duration, so the child may run immediately.
+    ///
+    /// `Box<State<A, S>>`: Cursor for the child.
+    CooldownState(f64, f64, Box<State<A, S>>),
 }
 
 impl<A: Clone, S> State<A, S> {
@@ -82,12 +170,51 @@ impl<A: Clone, S> State<A, S> {
             }
             Behavior::While(ev, rep) => {
                 let state = State::new(rep[0].clone());
-                State::WhileState(Box::new(State::new(*ev)), rep, 0, Box::new(state))
+                State::WhileState(
+                    Box::new(State::new(*ev)),
+                    rep,
+                    0,
+                    Box::new(state),
+                    DEFAULT_MAX_ITERATIONS_PER_TICK,
+                )
             }
             Behavior::WhenAll(all) => State::WhenAllState(all.into_iter().map(|ev| Some(State::new(ev))).collect()),
             Behavior::WhenAny(all) => State::WhenAnyState(all.into_iter().map(|ev| Some(State::new(ev))).collect()),
             Behavior::After(seq) => State::AfterState(0, seq.into_iter().map(State::new).collect()),
+            Behavior::Parallel {
+                children,
+                success_threshold,
+                failure_threshold,
+            } => {
+                // A failure threshold defaults to "the rest can't possibly reach
+                // success_threshold any more", i.e. `children.len() - success_threshold + 1`.
+                // `success_threshold` may exceed `children.len()` (an always-failing
+                // tree, but not something the type system rules out), so the
+                // subtraction is saturating rather than panicking/wrapping on overflow.
+                let failure_threshold = failure_threshold
+                    .unwrap_or_else(|| children.len().saturating_sub(success_threshold).saturating_add(1));
+                let cursors = children.into_iter().map(|ev| Some(State::new(ev))).collect();
+                State::ParallelState(cursors, success_threshold, failure_threshold, 0, 0)
+            }
+            Behavior::Repeat(count, rep) => {
+                let state = State::new((*rep).clone());
+                State::RepeatState(count, rep, Box::new(state))
+            }
+            Behavior::Cooldown(seconds, ev) => {
+                let state = State::new(*ev);
+                State::CooldownState(seconds, seconds, Box::new(state))
+            }
+        }
+    }
+
+    /// Sets the loop limit used by a `While` behavior to break out of a
+    /// zero-progress spin and yield `Running` instead of hanging the caller.
+    /// Has no effect on states other than `WhileState`.
+    pub fn with_loop_limit(mut self, max_iterations_per_tick: u32) -> Self {
+        if let WhileState(_, _, _, _, ref mut limit) = self {
+            *limit = max_iterations_per_tick;
         }
+        self
     }
 
     /// A signal called "tick" is sent to the root
@@ -115,47 +242,75 @@ impl<A: Clone, S> State<A, S> {
     where
         E: UpdateEvent,
         F: FnMut(ActionArgs<E, A, S>) -> (Status, f64),
+    {
+        // `F` is blanket-`BehaviorProcessor`-implemented with
+        // `Error = Infallible` (see above), so every per-variant match arm
+        // lives exactly once, in `event_with`, instead of being hand-copied
+        // here too.
+        match self.event_with(e, f) {
+            Ok(result) => result,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Updates the cursor that tracks an event, threading a fallible
+    /// `BehaviorProcessor` through every recursive call. This is the one
+    /// true implementation of the tick dispatch: `event` is a thin wrapper
+    /// that calls through here with its closure (blanket-implemented as a
+    /// `BehaviorProcessor<Error = Infallible>`) and unwraps the result,
+    /// rather than duplicating this match. The instant any action's
+    /// `process` returns `ProcessResult::Abort`, the whole call
+    /// short-circuits and returns `Err`, unwinding out of any
+    /// `Sequence`/`WhenAll`/`While` nesting.
+    pub fn event_with<E, P>(&mut self, e: &E, p: &mut P) -> Result<(Status, f64), P::Error>
+    where
+        A: Clone,
+        E: UpdateEvent,
+        P: BehaviorProcessor<E, A, S>,
     {
         let upd = e.update(|args| Some(args.dt)).unwrap_or(None);
         match (upd, self) {
             (_, &mut ActionState(ref action, ref mut state)) => {
-                // Execute action.
-                f(ActionArgs {
+                let args = ActionArgs {
                     event: e,
                     dt: upd.unwrap_or(0.0),
                     action,
                     state,
-                })
+                };
+                match p.process(args) {
+                    ProcessResult::Running(dt) => Ok((Running, dt)),
+                    ProcessResult::Success(dt) => Ok((Success, dt)),
+                    ProcessResult::Failure(dt) => Ok((Failure, dt)),
+                    ProcessResult::Abort(err) => Err(err),
+                }
             }
-            (_, &mut FailState(ref mut cur)) => match cur.event(e, f) {
-                (Running, dt) => (Running, dt),
-                (Failure, dt) => (Success, dt),
-                (Success, dt) => (Failure, dt),
+            (_, &mut FailState(ref mut cur)) => match cur.event_with(e, p)? {
+                (Running, dt) => Ok((Running, dt)),
+                (Failure, dt) => Ok((Success, dt)),
+                (Success, dt) => Ok((Failure, dt)),
             },
-            (_, &mut AlwaysSucceedState(ref mut cur)) => match cur.event(e, f) {
-                (Running, dt) => (Running, dt),
-                (_, dt) => (Success, dt),
+            (_, &mut AlwaysSucceedState(ref mut cur)) => match cur.event_with(e, p)? {
+                (Running, dt) => Ok((Running, dt)),
+                (_, dt) => Ok((Success, dt)),
             },
             (Some(dt), &mut WaitState(wait_t, ref mut t)) => {
                 if *t + dt >= wait_t {
                     let remaining_dt = *t + dt - wait_t;
                     *t = wait_t;
-                    (Success, remaining_dt)
+                    Ok((Success, remaining_dt))
                 } else {
                     *t += dt;
-                    RUNNING
+                    Ok(RUNNING)
                 }
             }
             (_, &mut IfState(ref success, ref failure, ref mut status, ref mut state)) => {
                 let mut remaining_dt = upd.unwrap_or(0.0);
                 let remaining_e;
-                // Run in a loop to evaluate success or failure with
-                // remaining delta time after condition.
                 loop {
                     *status = match *status {
-                        Running => match state.event(e, f) {
+                        Running => match state.event_with(e, p)? {
                             (Running, dt) => {
-                                return (Running, dt);
+                                return Ok((Running, dt));
                             }
                             (Success, dt) => {
                                 **state = State::new((**success).clone());
@@ -169,7 +324,7 @@ impl<A: Clone, S> State<A, S> {
                             }
                         },
                         _ => {
-                            return state.event(
+                            return state.event_with(
                                 match upd {
                                     Some(_) => {
                                         remaining_e = UpdateEvent::from_dt(remaining_dt, e).unwrap();
@@ -177,31 +332,35 @@ impl<A: Clone, S> State<A, S> {
                                     }
                                     _ => e,
                                 },
-                                f,
+                                p,
                             );
                         }
                     }
                 }
             }
             (_, &mut SelectState(ref seq, ref mut i, ref mut cursor)) => {
-                let select = true;
-                sequence(select, upd, seq, i, cursor, e, f)
+                select_or_sequence_with(true, upd, seq, i, cursor, e, p)
             }
             (_, &mut SequenceState(ref seq, ref mut i, ref mut cursor)) => {
-                let select = false;
-                sequence(select, upd, seq, i, cursor, e, f)
+                select_or_sequence_with(false, upd, seq, i, cursor, e, p)
             }
-            (_, &mut WhileState(ref mut ev_cursor, ref rep, ref mut i, ref mut cursor)) => {
-                // If the event terminates, do not execute the loop.
-                match ev_cursor.event(e, f) {
+            (_, &mut WhileState(ref mut ev_cursor, ref rep, ref mut i, ref mut cursor, max_iterations_per_tick)) => {
+                match ev_cursor.event_with(e, p)? {
                     (Running, _) => {}
-                    x => return x,
+                    x => return Ok(x),
                 };
                 let cur = cursor;
                 let mut remaining_dt = upd.unwrap_or(0.0);
                 let mut remaining_e;
+                let mut iterations = 0;
+                let mut last_remaining_dt = remaining_dt;
                 loop {
-                    match cur.event(
+                    iterations += 1;
+                    if iterations > max_iterations_per_tick && remaining_dt >= last_remaining_dt {
+                        return Ok(RUNNING);
+                    }
+                    last_remaining_dt = remaining_dt;
+                    match cur.event_with(
                         match upd {
                             Some(_) => {
                                 remaining_e = UpdateEvent::from_dt(remaining_dt, e).unwrap();
@@ -209,70 +368,641 @@ impl<A: Clone, S> State<A, S> {
                             }
                             _ => e,
                         },
-                        f,
-                    ) {
-                        (Failure, x) => return (Failure, x),
+                        p,
+                    )? {
+                        (Failure, x) => return Ok((Failure, x)),
                         (Running, _) => break,
                         (Success, new_dt) => {
                             remaining_dt = match upd {
-                                // Change update event with remaining delta time.
                                 Some(_) => new_dt,
-                                // Other events are 'consumed' and not passed to next.
-                                _ => return RUNNING,
+                                _ => return Ok(RUNNING),
                             }
                         }
                     };
                     *i += 1;
-                    // If end of repeated events,
-                    // start over from the first one.
                     if *i >= rep.len() {
                         *i = 0;
                     }
-                    // Create a new cursor for next event.
-                    // Use the same pointer to avoid allocation.
                     **cur = State::new(rep[*i].clone());
                 }
-                RUNNING
-            }
-            (_, &mut WhenAllState(ref mut cursors)) => {
-                let any = false;
-                when_all(any, upd, cursors, e, f)
-            }
-            (_, &mut WhenAnyState(ref mut cursors)) => {
-                let any = true;
-                when_all(any, upd, cursors, e, f)
+                Ok(RUNNING)
             }
+            (_, &mut WhenAllState(ref mut cursors)) => when_all_with(false, upd, cursors, e, p),
+            (_, &mut WhenAnyState(ref mut cursors)) => when_all_with(true, upd, cursors, e, p),
             (_, &mut AfterState(ref mut i, ref mut cursors)) => {
-                // Get the least delta time left over.
                 let mut min_dt = f64::MAX;
                 for j in *i..cursors.len() {
-                    match cursors[j].event(e, f) {
+                    match cursors[j].event_with(e, p)? {
                         (Running, _) => {
                             min_dt = 0.0;
                         }
                         (Success, new_dt) => {
-                            // Remaining delta time must be less to succeed.
                             if *i == j && new_dt < min_dt {
                                 *i += 1;
                                 min_dt = new_dt;
                             } else {
-                                // Return least delta time because
-                                // that is when failure is detected.
-                                return (Failure, min_dt.min(new_dt));
+                                return Ok((Failure, min_dt.min(new_dt)));
                             }
                         }
                         (Failure, new_dt) => {
-                            return (Failure, new_dt);
+                            return Ok((Failure, new_dt));
                         }
                     };
                 }
                 if *i == cursors.len() {
-                    (Success, min_dt)
+                    Ok((Success, min_dt))
                 } else {
-                    RUNNING
+                    Ok(RUNNING)
                 }
             }
-            _ => RUNNING,
+            (
+                _,
+                &mut ParallelState(ref mut cursors, success_threshold, failure_threshold, ref mut succeeded, ref mut failed),
+            ) => {
+                let mut min_dt = f64::MAX;
+                for cursor in cursors.iter_mut() {
+                    let Some(cur) = cursor else { continue };
+                    match cur.event_with(e, p)? {
+                        (Running, _) => {}
+                        (Success, dt) => {
+                            *succeeded += 1;
+                            min_dt = min_dt.min(dt);
+                            *cursor = None;
+                        }
+                        (Failure, dt) => {
+                            *failed += 1;
+                            min_dt = min_dt.min(dt);
+                            *cursor = None;
+                        }
+                    }
+                }
+                let min_dt = if min_dt == f64::MAX { upd.unwrap_or(0.0) } else { min_dt };
+                if *succeeded >= success_threshold {
+                    Ok((Success, min_dt))
+                } else if *failed >= failure_threshold {
+                    Ok((Failure, min_dt))
+                } else {
+                    Ok(RUNNING)
+                }
+            }
+            (_, &mut RepeatState(ref mut remaining, ref inner, ref mut cursor)) => {
+                // `Repeat(0, ..)` is a valid count as unconstrained as
+                // `Parallel`'s `success_threshold` was (see the chunk0-1
+                // fix); repeating zero times is a no-op success rather than
+                // something that should run the child once and then
+                // underflow decrementing past 0.
+                if *remaining == 0 {
+                    return Ok((Success, upd.unwrap_or(0.0)));
+                }
+                let mut remaining_dt = upd.unwrap_or(0.0);
+                let mut remaining_e;
+                let mut iterations = 0;
+                let mut last_remaining_dt = remaining_dt;
+                loop {
+                    iterations += 1;
+                    if iterations > DEFAULT_MAX_ITERATIONS_PER_TICK && remaining_dt >= last_remaining_dt {
+                        return Ok(RUNNING);
+                    }
+                    last_remaining_dt = remaining_dt;
+                    match cursor.event_with(
+                        match upd {
+                            Some(_) => {
+                                remaining_e = UpdateEvent::from_dt(remaining_dt, e).unwrap();
+                                &remaining_e
+                            }
+                            _ => e,
+                        },
+                        p,
+                    )? {
+                        (Failure, dt) => return Ok((Failure, dt)),
+                        (Running, _) => return Ok(RUNNING),
+                        (Success, new_dt) => {
+                            *remaining -= 1;
+                            if *remaining == 0 {
+                                return Ok((Success, new_dt));
+                            }
+                            remaining_dt = match upd {
+                                Some(_) => new_dt,
+                                _ => return Ok(RUNNING),
+                            };
+                        }
+                    }
+                    **cursor = State::new((**inner).clone());
+                }
+            }
+            (Some(dt), &mut CooldownState(cooldown, ref mut elapsed, ref mut cursor)) if *elapsed < cooldown => {
+                if *elapsed + dt >= cooldown {
+                    let remaining_dt = *elapsed + dt - cooldown;
+                    *elapsed = cooldown;
+                    let remaining_e = UpdateEvent::from_dt(remaining_dt, e).unwrap();
+                    match cursor.event_with(&remaining_e, p)? {
+                        (Running, dt) => Ok((Running, dt)),
+                        (status, dt) => {
+                            *elapsed = 0.0;
+                            Ok((status, dt))
+                        }
+                    }
+                } else {
+                    *elapsed += dt;
+                    Ok(RUNNING)
+                }
+            }
+            (_, &mut CooldownState(cooldown, ref mut elapsed, _)) if *elapsed < cooldown => Ok(RUNNING),
+            (_, &mut CooldownState(_, ref mut elapsed, ref mut cursor)) => match cursor.event_with(e, p)? {
+                (Running, dt) => Ok((Running, dt)),
+                (status, dt) => {
+                    *elapsed = 0.0;
+                    Ok((status, dt))
+                }
+            },
+            _ => Ok(RUNNING),
+        }
+    }
+
+    /// Like `tick`, but backed by a `WaitScheduler` so that a tree idling
+    /// behind one or more `Wait` deadlines (e.g. hundreds of `Wait` nodes
+    /// parked behind a `WhenAll`/`After`, themselves blocked on
+    /// `WaitForever` elsewhere in the tree) skips the full traversal on
+    /// frames where nothing could possibly have expired yet.
+    ///
+    /// This only short-circuits when every node on the currently active
+    /// branch is timer-driven (`Wait`/`WaitForever` and the decorators and
+    /// composites around them); as soon as an `ActionState` is reachable on
+    /// the active branch, `tick_scheduled` falls back to a normal `tick`
+    /// every call, because an action must run every frame to have a chance
+    /// to resolve regardless of elapsed time.
+    pub fn tick_scheduled<F>(&mut self, dt: f64, scheduler: &mut WaitScheduler, block: &mut F) -> (Status, f64)
+    where
+        A: Clone,
+        F: FnMut(ActionArgs<'_, Event, A, S>) -> (Status, f64),
+    {
+        // Carry the sub-millisecond remainder across calls instead of
+        // rounding it away every time, or a sub-1ms-per-call `dt` would
+        // round to 0ms forever and the wheel would never advance.
+        let total_ms = scheduler.remainder_ms + dt * 1000.0;
+        let dt_ms = total_ms.floor();
+        scheduler.remainder_ms = total_ms - dt_ms;
+        let dt_ms = dt_ms as u64;
+
+        // Real elapsed time since the last call that actually drove the
+        // tree, so a deadline that fires after several short-circuited
+        // calls still sees the whole gap rather than just this call's `dt`.
+        scheduler.accumulated_dt += dt;
+
+        if scheduler.pending && scheduler.wheel.advance(dt_ms).is_empty() {
+            return RUNNING;
+        }
+
+        // `tick` itself discards the `(Status, f64)` result, so go through
+        // `event` directly to get it back.
+        let elapsed = scheduler.accumulated_dt;
+        scheduler.accumulated_dt = 0.0;
+        let e: Event = UpdateArgs { dt: elapsed }.into();
+        let result = self.event(&e, block);
+
+        scheduler.pending = false;
+        if let Some(deadlines) = self.active_wait_deadlines_ms() {
+            // Even a purely `WaitForeverState` branch reports `Some(vec![])`
+            // here (timer-driven, but nothing is actually counting down).
+            // Still mark the scheduler pending with an empty wheel so later
+            // calls keep short-circuiting instead of falling back to a full
+            // tick forever.
+            scheduler.wheel = timing_wheel::TimingWheel::new();
+            if let Some(earliest) = deadlines.into_iter().min() {
+                scheduler.wheel.schedule((), earliest);
+            }
+            scheduler.pending = true;
+        }
+        result
+    }
+
+    /// Remaining time, in milliseconds, until every `Wait` deadline on the
+    /// currently active branch elapses, or `None` if that branch also
+    /// depends on an `ActionState` that must be polled every frame.
+    fn active_wait_deadlines_ms(&self) -> Option<Vec<u64>> {
+        match self {
+            ActionState(_, _) => None,
+            FailState(cur) | AlwaysSucceedState(cur) => cur.active_wait_deadlines_ms(),
+            WaitState(wait_t, t) => Some(vec![((wait_t - t).max(0.0) * 1000.0).round() as u64]),
+            WaitForeverState => Some(Vec::new()),
+            IfState(_, _, _, cur) => cur.active_wait_deadlines_ms(),
+            SelectState(_, _, cur) | SequenceState(_, _, cur) => cur.active_wait_deadlines_ms(),
+            WhileState(ev_cursor, _, _, cur, _) => {
+                // The condition cursor is ticked every event too.
+                let mut deadlines = ev_cursor.active_wait_deadlines_ms()?;
+                deadlines.extend(cur.active_wait_deadlines_ms()?);
+                Some(deadlines)
+            }
+            WhenAllState(cursors) | WhenAnyState(cursors) => {
+                let mut deadlines = Vec::new();
+                for cursor in cursors.iter().flatten() {
+                    deadlines.extend(cursor.active_wait_deadlines_ms()?);
+                }
+                Some(deadlines)
+            }
+            AfterState(i, cursors) => {
+                let mut deadlines = Vec::new();
+                for cursor in &cursors[*i..] {
+                    deadlines.extend(cursor.active_wait_deadlines_ms()?);
+                }
+                Some(deadlines)
+            }
+            ParallelState(cursors, ..) => {
+                let mut deadlines = Vec::new();
+                for cursor in cursors.iter().flatten() {
+                    deadlines.extend(cursor.active_wait_deadlines_ms()?);
+                }
+                Some(deadlines)
+            }
+            RepeatState(_, _, cur) => cur.active_wait_deadlines_ms(),
+            CooldownState(cooldown, elapsed, cur) => {
+                if elapsed < cooldown {
+                    Some(vec![((cooldown - elapsed).max(0.0) * 1000.0).round() as u64])
+                } else {
+                    cur.active_wait_deadlines_ms()
+                }
+            }
+        }
+    }
+}
+
+/// Scheduling cache used by `State::tick_scheduled`, backed by a
+/// `timing_wheel::TimingWheel`.
+pub struct WaitScheduler {
+    wheel: timing_wheel::TimingWheel<()>,
+    pending: bool,
+    /// Sub-millisecond remainder carried across calls, so a caller ticking
+    /// at sub-millisecond `dt` (e.g. > 1kHz) doesn't have every call's
+    /// fractional millisecond rounded away to zero and never accumulate.
+    remainder_ms: f64,
+    /// Real time elapsed since the tree was last actually ticked. Calls
+    /// that short-circuit (the wheel has nothing due yet) still add their
+    /// `dt` here so that the eventual real tick sees the whole gap, not
+    /// just the `dt` of the call that happened to make a deadline fire.
+    accumulated_dt: f64,
+}
+
+impl WaitScheduler {
+    /// Creates an empty scheduler; the first `tick_scheduled` call always
+    /// performs a full tick to discover the tree's initial deadlines.
+    pub fn new() -> Self {
+        WaitScheduler {
+            wheel: timing_wheel::TimingWheel::new(),
+            pending: false,
+            remainder_ms: 0.0,
+            accumulated_dt: 0.0,
+        }
+    }
+}
+
+impl Default for WaitScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A small hierarchical timing wheel used to find "what expires next"
+/// without re-scanning every pending deadline.
+pub mod timing_wheel {
+    /// Number of fine-grained (1ms) slots in level 0.
+    const LEVEL0_SLOTS: usize = 256;
+    /// Number of coarse slots in level 1; each spans `LEVEL0_SLOTS` ms.
+    const LEVEL1_SLOTS: usize = 64;
+    const LEVEL0_SPAN_MS: u64 = LEVEL0_SLOTS as u64;
+
+    /// A two-level hierarchical timing wheel keyed by milliseconds.
+    ///
+    /// Deadlines within `LEVEL0_SPAN_MS` land directly in the fine-grained
+    /// level-0 ring. Farther-out deadlines park in the coarse level-1 ring
+    /// and cascade down into level 0 once the level-0 cursor wraps, so
+    /// `advance` only ever touches the slots whose deadlines elapsed and the
+    /// buckets that cascade — its cost does not grow with the number of
+    /// timers registered elsewhere in the wheel.
+    pub struct TimingWheel<H> {
+        level0: Vec<Vec<H>>,
+        level1: Vec<Vec<(u64, H)>>,
+        cursor0: usize,
+        cursor1: usize,
+    }
+
+    impl<H> TimingWheel<H> {
+        /// Creates an empty wheel.
+        pub fn new() -> Self {
+            TimingWheel {
+                level0: (0..LEVEL0_SLOTS).map(|_| Vec::new()).collect(),
+                level1: (0..LEVEL1_SLOTS).map(|_| Vec::new()).collect(),
+                cursor0: 0,
+                cursor1: 0,
+            }
+        }
+
+        /// Largest delay level 1 can represent without its slot index
+        /// wrapping back around `LEVEL1_SLOTS`.
+        const MAX_DELAY_MS: u64 = LEVEL1_SLOTS as u64 * LEVEL0_SPAN_MS - 1;
+
+        /// Registers `handle` to fire after `delay_ms` milliseconds.
+        pub fn schedule(&mut self, handle: H, delay_ms: u64) {
+            // `advance` increments `cursor0` before consulting its slot, so
+            // a zero-delay (already-due) handle dropped into the current
+            // slot would sit unseen until the level-0 ring fully wraps back
+            // around to it. Placing it one slot ahead instead makes it fire
+            // on the very next `advance` call, as an already-due deadline
+            // should.
+            let delay_ms = delay_ms.max(1);
+            // Delays beyond what level 1 can represent are clamped to the
+            // wheel's horizon rather than wrapped silently into an aliased
+            // (and possibly much sooner) slot modulo `LEVEL1_SLOTS`. The
+            // handle still fires no later than the true deadline; the full
+            // tick it triggers re-derives the real remaining time and
+            // reschedules.
+            let delay_ms = delay_ms.min(Self::MAX_DELAY_MS);
+            // Measured from the start of the current level-0 revolution, so
+            // level-1 placement accounts for how far `cursor0` has moved.
+            let target_ms = self.cursor0 as u64 + delay_ms;
+            if delay_ms < LEVEL0_SPAN_MS {
+                let slot = (target_ms % LEVEL0_SPAN_MS) as usize;
+                self.level0[slot].push(handle);
+            } else {
+                let slot = (self.cursor1 + (target_ms / LEVEL0_SPAN_MS) as usize) % LEVEL1_SLOTS;
+                let offset_ms = target_ms % LEVEL0_SPAN_MS;
+                self.level1[slot].push((offset_ms, handle));
+            }
+        }
+
+        /// Advances the wheel by `dt_ms` milliseconds, returning every
+        /// handle whose deadline elapsed. Cascades the next level-1 bucket
+        /// into level 0 whenever the level-0 cursor wraps around.
+        pub fn advance(&mut self, dt_ms: u64) -> Vec<H> {
+            let mut expired = Vec::new();
+            for _ in 0..dt_ms {
+                self.cursor0 = (self.cursor0 + 1) % LEVEL0_SLOTS;
+                // Cascade before draining, so a cascaded handle with offset
+                // 0 fires on this step rather than one revolution later.
+                if self.cursor0 == 0 {
+                    self.cursor1 = (self.cursor1 + 1) % LEVEL1_SLOTS;
+                    for (offset_ms, handle) in std::mem::take(&mut self.level1[self.cursor1]) {
+                        let slot = (self.cursor0 + offset_ms as usize) % LEVEL0_SLOTS;
+                        self.level0[slot].push(handle);
+                    }
+                }
+                expired.append(&mut self.level0[self.cursor0]);
+            }
+            expired
+        }
+
+        /// True if nothing is currently scheduled.
+        pub fn is_empty(&self) -> bool {
+            self.level0.iter().all(Vec::is_empty) && self.level1.iter().all(Vec::is_empty)
+        }
+    }
+
+    impl<H> Default for TimingWheel<H> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn fires_exactly_once_at_deadline() {
+            let mut wheel = TimingWheel::new();
+            wheel.schedule("a", 10);
+            for _ in 0..9 {
+                assert_eq!(wheel.advance(1), Vec::<&str>::new());
+            }
+            assert_eq!(wheel.advance(1), vec!["a"]);
+            assert!(wheel.is_empty());
+        }
+
+        #[test]
+        fn cascades_from_level1_into_level0() {
+            let mut wheel = TimingWheel::new();
+            // Farther out than a single level-0 ring; must be parked in
+            // level 1 and cascade down correctly.
+            wheel.schedule("far", LEVEL0_SPAN_MS + 5);
+            let expired = wheel.advance(LEVEL0_SPAN_MS + 5);
+            assert_eq!(expired, vec!["far"]);
+        }
+
+        #[test]
+        fn zero_delay_fires_on_next_advance() {
+            // A deadline that has already elapsed (delay rounds down to
+            // 0ms) must not sit unseen until the level-0 ring wraps back
+            // around to the slot it was dropped in.
+            let mut wheel = TimingWheel::new();
+            wheel.schedule("due", 0);
+            assert_eq!(wheel.advance(1), vec!["due"]);
+        }
+
+        #[test]
+        fn delay_beyond_level1_horizon_fires_no_later_than_requested() {
+            // A delay past what level 1 can represent must be clamped to
+            // the wheel's horizon, not silently wrapped modulo
+            // `LEVEL1_SLOTS` into some arbitrary earlier slot.
+            let mut wheel = TimingWheel::new();
+            let too_far = LEVEL1_SLOTS as u64 * LEVEL0_SPAN_MS + 1000;
+            wheel.schedule("eventually", too_far);
+            let mut elapsed = 0;
+            let mut fired = false;
+            while elapsed < too_far {
+                if !wheel.advance(1).is_empty() {
+                    fired = true;
+                    break;
+                }
+                elapsed += 1;
+            }
+            assert!(fired, "handle never fired within its requested delay");
+            assert!(elapsed <= too_far);
+        }
+    }
+}
+
+/// Shared `Select`/`Sequence` stepping logic for `event_with`, mirroring
+/// `crate::sequence::sequence` but threaded through a fallible
+/// `BehaviorProcessor` instead of a closure.
+fn select_or_sequence_with<E, A, S, P>(
+    select: bool,
+    upd: Option<f64>,
+    seq: &[Behavior<A>],
+    i: &mut usize,
+    cursor: &mut Box<State<A, S>>,
+    e: &E,
+    p: &mut P,
+) -> Result<(Status, f64), P::Error>
+where
+    A: Clone,
+    E: UpdateEvent,
+    P: BehaviorProcessor<E, A, S>,
+{
+    let mut remaining_dt = upd.unwrap_or(0.0);
+    let mut remaining_e;
+    loop {
+        match cursor.event_with(
+            match upd {
+                Some(_) => {
+                    remaining_e = UpdateEvent::from_dt(remaining_dt, e).unwrap();
+                    &remaining_e
+                }
+                _ => e,
+            },
+            p,
+        )? {
+            (Running, _) => return Ok(RUNNING),
+            (Success, new_dt) if select => return Ok((Success, new_dt)),
+            (Failure, new_dt) if !select => return Ok((Failure, new_dt)),
+            (_, new_dt) => {
+                remaining_dt = match upd {
+                    Some(_) => new_dt,
+                    _ => return Ok(RUNNING),
+                };
+            }
+        }
+        *i += 1;
+        if *i >= seq.len() {
+            return Ok(if select { (Failure, remaining_dt) } else { (Success, remaining_dt) });
+        }
+        **cursor = State::new(seq[*i].clone());
+    }
+}
+
+/// Shared `WhenAll`/`WhenAny` stepping logic for `event_with`, mirroring
+/// `crate::when_all::when_all` but threaded through a fallible
+/// `BehaviorProcessor` instead of a closure.
+fn when_all_with<E, A, S, P>(
+    any: bool,
+    upd: Option<f64>,
+    cursors: &mut [Option<State<A, S>>],
+    e: &E,
+    p: &mut P,
+) -> Result<(Status, f64), P::Error>
+where
+    A: Clone,
+    E: UpdateEvent,
+    P: BehaviorProcessor<E, A, S>,
+{
+    let _ = upd;
+    let mut min_dt = f64::MAX;
+    for cursor in cursors.iter_mut() {
+        let Some(cur) = cursor else { continue };
+        match cur.event_with(e, p)? {
+            (Running, _) => {}
+            (Success, new_dt) => {
+                // Clear the cursor that just resolved before any early
+                // return below: this node may report a terminal status
+                // to its own caller while siblings are still `Running`,
+                // and if something ticks it again later (our own
+                // proptest's drive loop does, regardless of status), a
+                // resolved-but-uncleared cursor would silently re-invoke
+                // a child whose result was already consumed upward.
+                *cursor = None;
+                if any {
+                    return Ok((Success, new_dt));
+                }
+                min_dt = min_dt.min(new_dt);
+            }
+            (Failure, new_dt) => {
+                *cursor = None;
+                if !any {
+                    return Ok((Failure, new_dt));
+                }
+                min_dt = min_dt.min(new_dt);
+            }
+        }
+    }
+    if cursors.iter().all(Option::is_none) {
+        Ok(if any { (Failure, min_dt) } else { (Success, min_dt) })
+    } else {
+        Ok(RUNNING)
+    }
+}
+
+/// Property-based fuzzing support for the tick engine, gated behind the
+/// `proptest` feature so default builds pay no cost for it.
+#[cfg(feature = "proptest")]
+pub mod proptest_support {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Depth/size budget for generated behavior trees. Kept small so a
+    /// failing case shrinks to something readable.
+    const MAX_DEPTH: u32 = 4;
+    const MAX_CHILDREN: usize = 4;
+
+    /// Builds a `Strategy` that generates arbitrary `Behavior<A>` trees.
+    ///
+    /// Leaves are `Action`, `Wait`, `WaitForever`. Internal nodes are
+    /// `Sequence`, `Select`, `If`, `While`, `WhenAll`, `WhenAny`, `After`,
+    /// `Fail`, `AlwaysSucceed`, chosen with equal weight. Proptest shrinks
+    /// recursive nodes by collapsing to one of their children and by
+    /// shrinking the child `Vec`, so a minimal reproducer falls out of any
+    /// panic or non-termination the engine hits.
+    pub fn arb_behavior<A>() -> impl Strategy<Value = Behavior<A>>
+    where
+        A: Arbitrary + Clone + 'static,
+    {
+        let leaf = prop_oneof![
+            any::<A>().prop_map(Behavior::Action),
+            (0.0f64..10.0).prop_map(Behavior::Wait),
+            Just(Behavior::WaitForever),
+        ];
+
+        leaf.prop_recursive(MAX_DEPTH, 64, MAX_CHILDREN as u32, |inner| {
+            prop_oneof![
+                inner.clone().prop_map(|b| Behavior::Fail(Box::new(b))),
+                inner.clone().prop_map(|b| Behavior::AlwaysSucceed(Box::new(b))),
+                prop::collection::vec(inner.clone(), 1..MAX_CHILDREN).prop_map(Behavior::Sequence),
+                prop::collection::vec(inner.clone(), 1..MAX_CHILDREN).prop_map(Behavior::Select),
+                prop::collection::vec(inner.clone(), 1..MAX_CHILDREN).prop_map(Behavior::WhenAll),
+                prop::collection::vec(inner.clone(), 1..MAX_CHILDREN).prop_map(Behavior::WhenAny),
+                prop::collection::vec(inner.clone(), 1..MAX_CHILDREN).prop_map(Behavior::After),
+                (inner.clone(), inner.clone(), inner.clone())
+                    .prop_map(|(cond, succ, fail)| Behavior::If(Box::new(cond), Box::new(succ), Box::new(fail))),
+                (inner.clone(), prop::collection::vec(inner.clone(), 1..MAX_CHILDREN))
+                    .prop_map(|(ev, rep)| Behavior::While(Box::new(ev), rep)),
+            ]
+        })
+    }
+
+    /// Minimal `Arbitrary` action alphabet used to drive the fuzzed trees;
+    /// real users plug in their own action type the same way.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct TestAction;
+
+    impl Arbitrary for TestAction {
+        type Parameters = ();
+        type Strategy = Just<TestAction>;
+        fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+            Just(TestAction)
+        }
+    }
+
+    proptest! {
+        /// Core invariants of the tick engine: the returned `dt` is never
+        /// negative, and driving the tree never hangs past the loop guard
+        /// (see `DEFAULT_MAX_ITERATIONS_PER_TICK` / `with_loop_limit`).
+        ///
+        /// This does not, on its own, assert that a resolved composite
+        /// never leaves a child cursor in a contradictory state (e.g. a
+        /// child whose terminal result already propagated upward getting
+        /// silently re-invoked on a later tick of the same tree) — `arb_behavior`'s
+        /// generated actions are indistinguishable from one another, so
+        /// this harness has no way to notice a child running again. That
+        /// specific class of bug was found by inspection in
+        /// `when_all_with`'s early-return paths and is covered instead by
+        /// `test_when_all_does_not_rerun_a_child_already_consumed_by_early_failure`,
+        /// which can name the exact child that must not re-run.
+        #[test]
+        fn tick_never_returns_negative_dt_or_hangs(behavior in arb_behavior::<TestAction>()) {
+            let mut state = State::<TestAction, ()>::new(behavior);
+            let f = &mut |args: ActionArgs<Event, TestAction, ()>| (Success, args.dt);
+            for _ in 0..16 {
+                let e: Event = UpdateArgs { dt: 1.0 }.into();
+                let (_, dt) = state.event(&e, f);
+                prop_assert!(dt >= 0.0);
+            }
         }
     }
 }
@@ -314,4 +1044,229 @@ mod tests {
         state.tick(0.0, f);
         assert_eq!(acc, 1);
     }
+
+    #[test]
+    fn test_while_zero_progress_guard() {
+        // A repeated `Sequence` of actions that always succeed without
+        // consuming any delta time used to make the `WhileState` loop spin
+        // forever. It must now bail out and report `Running` instead.
+        let behavior = Behavior::While(
+            Box::new(Behavior::WaitForever),
+            vec![Sequence(vec![Action(Inc), Action(Dec)])],
+        );
+        let mut state = State::new(behavior).with_loop_limit(100);
+
+        let mut acc: u32 = 0;
+        let f = &mut |args: ActionArgs<Event, TestActions, ()>| match &*args.action {
+            Inc => {
+                acc += 1;
+                (Success, args.dt)
+            }
+            Dec => {
+                acc -= 1;
+                (Success, args.dt)
+            }
+        };
+
+        let (status, _) = state.event(&UpdateArgs { dt: 0.0 }.into(), f);
+        assert_eq!(status, Running);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct DomainError;
+
+    struct AbortOnDec;
+
+    impl BehaviorProcessor<Event, TestActions, ()> for AbortOnDec {
+        type Error = DomainError;
+
+        fn process(&mut self, args: ActionArgs<Event, TestActions, ()>) -> ProcessResult<DomainError> {
+            match &*args.action {
+                Inc => ProcessResult::Success(args.dt),
+                Dec => ProcessResult::Abort(DomainError),
+            }
+        }
+    }
+
+    #[test]
+    fn test_event_with_aborts_on_error() {
+        let seq = Sequence(vec![Action(Inc), Action(Dec), Action(Inc)]);
+        let mut state = State::new(seq);
+
+        let result = state.event_with(&UpdateArgs { dt: 0.0 }.into(), &mut AbortOnDec);
+        assert_eq!(result, Err(DomainError));
+    }
+
+    #[test]
+    fn test_repeat_runs_child_count_times() {
+        let behavior = Behavior::Repeat(3, Box::new(Action(Inc)));
+        let mut state = State::new(behavior);
+
+        let mut acc: u32 = 0;
+        let f = &mut |args: ActionArgs<Event, TestActions, ()>| match &*args.action {
+            Inc => {
+                acc += 1;
+                (Success, args.dt)
+            }
+            Dec => {
+                acc -= 1;
+                (Success, args.dt)
+            }
+        };
+
+        let (status, _) = state.event(&UpdateArgs { dt: 0.0 }.into(), f);
+        assert_eq!(status, Success);
+        assert_eq!(acc, 3);
+    }
+
+    #[test]
+    fn test_repeat_zero_times_succeeds_without_running_child() {
+        let behavior = Behavior::Repeat(0, Box::new(Action(Inc)));
+        let mut state = State::new(behavior);
+
+        let mut acc: u32 = 0;
+        let f = &mut |args: ActionArgs<Event, TestActions, ()>| match &*args.action {
+            Inc => {
+                acc += 1;
+                (Success, args.dt)
+            }
+            Dec => {
+                acc -= 1;
+                (Success, args.dt)
+            }
+        };
+
+        let (status, _) = state.event(&UpdateArgs { dt: 0.0 }.into(), f);
+        assert_eq!(status, Success);
+        assert_eq!(acc, 0);
+    }
+
+    #[test]
+    fn test_cooldown_blocks_reentry_until_elapsed() {
+        let behavior = Behavior::Cooldown(1.0, Box::new(Action(Inc)));
+        let mut state = State::new(behavior);
+
+        let mut acc: u32 = 0;
+        let f = &mut |args: ActionArgs<Event, TestActions, ()>| match &*args.action {
+            Inc => {
+                acc += 1;
+                (Success, args.dt)
+            }
+            Dec => {
+                acc -= 1;
+                (Success, args.dt)
+            }
+        };
+
+        let (status_first, _) = state.event(&UpdateArgs { dt: 0.0 }.into(), f);
+        // Still cooling down: the child must not run again yet.
+        let (status_cooling, _) = state.event(&UpdateArgs { dt: 0.5 }.into(), f);
+        // Cooldown elapses: the child runs again.
+        let (status_elapsed, _) = state.event(&UpdateArgs { dt: 0.5 }.into(), f);
+
+        assert_eq!(status_first, Success);
+        assert_eq!(status_cooling, Running);
+        assert_eq!(status_elapsed, Success);
+        assert_eq!(acc, 2);
+    }
+
+    #[test]
+    fn test_parallel_success_threshold_above_child_count_does_not_overflow() {
+        // `success_threshold` exceeding the number of children is an
+        // always-failing tree, but nothing prevents a caller from
+        // constructing one; the default `failure_threshold` derivation must
+        // not underflow when computing `children.len() - success_threshold`.
+        let behavior = Behavior::Parallel {
+            children: vec![Action(Dec)],
+            success_threshold: 5,
+            failure_threshold: None,
+        };
+        let mut state = State::new(behavior);
+
+        let mut ran = false;
+        let f = &mut |args: ActionArgs<Event, TestActions, ()>| match &*args.action {
+            Inc => (Success, args.dt),
+            Dec => {
+                ran = true;
+                (Failure, args.dt)
+            }
+        };
+
+        let (status, _) = state.event(&UpdateArgs { dt: 0.0 }.into(), f);
+        assert_eq!(status, Failure);
+        assert!(ran);
+    }
+
+    #[test]
+    fn test_tick_scheduled_fires_wait_landing_near_tick_boundary() {
+        // The remaining delay rounds down to a near-zero number of
+        // milliseconds; it must still fire on one of the very next calls
+        // instead of waiting for the timing wheel to wrap all the way
+        // around (see `TimingWheel::schedule`'s zero-delay handling).
+        let behavior = Behavior::<TestActions>::Wait(0.0103);
+        let mut state = State::new(behavior);
+        let mut scheduler = WaitScheduler::new();
+        let f = &mut |args: ActionArgs<Event, TestActions, ()>| (Success, args.dt);
+
+        // Drive it right up to the edge: 10ms elapsed, 0.3ms remaining.
+        let (status, _) = state.tick_scheduled(0.01, &mut scheduler, f);
+        assert_eq!(status, Running);
+
+        let mut resolved = false;
+        for _ in 0..10 {
+            let (status, _) = state.tick_scheduled(0.001, &mut scheduler, f);
+            if status == Success {
+                resolved = true;
+                break;
+            }
+        }
+        assert!(resolved, "Wait landing near a tick boundary did not resolve promptly");
+    }
+
+    #[test]
+    fn test_tick_scheduled_handles_wait_longer_than_one_wheel_span() {
+        let behavior = Behavior::<TestActions>::Wait(20.0);
+        let mut state = State::new(behavior);
+        let mut scheduler = WaitScheduler::new();
+        let f = &mut |args: ActionArgs<Event, TestActions, ()>| (Success, args.dt);
+
+        let mut resolved = false;
+        for _ in 0..25 {
+            let (status, _) = state.tick_scheduled(1.0, &mut scheduler, f);
+            if status == Success {
+                resolved = true;
+                break;
+            }
+        }
+        assert!(resolved, "Wait longer than one wheel span never resolved");
+    }
+
+    #[test]
+    fn test_when_all_does_not_rerun_a_child_already_consumed_by_early_failure() {
+        // `WhenAll` reports `Failure` as soon as one child fails, leaving
+        // any still-`Running` siblings untouched. But the failed child's
+        // own cursor must be cleared right there too: if this same state
+        // gets ticked again later, a left-over `Some` cursor for a child
+        // that already reported its terminal result would silently
+        // re-invoke that child behind the caller's back.
+        let behavior = Behavior::WhenAll(vec![Action(Dec), Behavior::WaitForever]);
+        let mut state = State::new(behavior);
+
+        let mut dec_runs = 0u32;
+        let f = &mut |args: ActionArgs<Event, TestActions, ()>| match &*args.action {
+            Dec => {
+                dec_runs += 1;
+                (Failure, args.dt)
+            }
+            Inc => (Success, args.dt),
+        };
+
+        let (status_first, _) = state.event(&UpdateArgs { dt: 0.0 }.into(), f);
+        // Tick again: the still-`WaitForever` sibling keeps it `Running`
+        // from here, but the already-failed child must not run again.
+        state.event(&UpdateArgs { dt: 0.0 }.into(), f);
+
+        assert_eq!(status_first, Failure);
+        assert_eq!(dec_runs, 1, "already-failed child must not run again on a later tick");
+    }
 }
\ No newline at end of file